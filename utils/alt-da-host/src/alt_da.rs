@@ -0,0 +1,270 @@
+use std::future::Future;
+
+use alloy_primitives::{Address, B256, U256};
+use alloy_provider::ProviderBuilder;
+use alloy_sol_types::sol;
+use anyhow::Result;
+use async_trait::async_trait;
+use celo_host::single::CeloSingleChainHost;
+use kona_host::single::SingleChainHostError;
+use kona_preimage::Channel;
+use op_succinct_host_utils::{
+    fetcher::OPSuccinctDataFetcher,
+    host::OPSuccinctHost,
+    witness_generation::online::OnlineWitnessGenerator,
+};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+sol! {
+    /// Minimal view onto the `DataAvailabilityChallenge` contract: the L1 block number at which a
+    /// given L2 block's input commitment was recorded, or zero if no commitment has been posted
+    /// for that block yet. Commitment L1 block numbers are monotonically non-decreasing in the L2
+    /// block number, which is what lets [`find_finalized_boundary`] binary search for the finality
+    /// boundary instead of walking every block.
+    #[sol(rpc)]
+    interface IDataAvailabilityChallenge {
+        function commitmentL1Block(uint256 l2BlockNumber) external view returns (uint256);
+    }
+}
+
+/// Host implementation for an alt-DA (plasma, challenge/resolve) rollup, where input commitments
+/// are posted to an on-chain `DataAvailabilityChallenge` contract rather than to L1 calldata or
+/// blobs.
+///
+/// A block derived from alt-DA input is only safe to include in a range proof once the commitment
+/// backing it can no longer be challenged, or, if it was challenged, once the resolve window has
+/// elapsed. See [`AltDAChainHost::get_finalized_l2_block_number`] and
+/// [`AltDAChainHost::calculate_safe_l1_head`] for the gating logic.
+#[derive(Clone, Debug)]
+pub struct AltDAChainHost {
+    /// The wrapped single-chain host, which handles everything that isn't alt-DA-specific
+    /// (preimage serving, native derivation, etc).
+    pub single_chain_host: CeloSingleChainHost,
+    /// The address of the `DataAvailabilityChallenge` contract on L1.
+    pub da_challenge_contract_address: Address,
+    /// HTTP RPC URL used to query the `DataAvailabilityChallenge` contract directly. Kept
+    /// separate from `OPSuccinctDataFetcher`, which only exposes generic L1 helpers, not
+    /// arbitrary contract calls.
+    pub l1_rpc_url: String,
+    /// The number of L1 blocks during which a posted commitment can be challenged.
+    pub da_challenge_window: u64,
+    /// The number of L1 blocks a challenger/proposer has to resolve a challenge once raised.
+    pub da_resolve_window: u64,
+}
+
+impl AltDAChainHost {
+    /// Start the preimage/hint server backing this host. Delegates to the wrapped
+    /// [`CeloSingleChainHost`], as alt-DA input commitments are resolved to their underlying data
+    /// before being served to the client program, the same way blobs are for ETH DA. The server
+    /// loop exits once `cancellation` is cancelled.
+    pub async fn start_server<C>(
+        &self,
+        hint: C,
+        preimage: C,
+        cancellation: CancellationToken,
+    ) -> Result<JoinHandle<Result<(), SingleChainHostError>>, SingleChainHostError>
+    where
+        C: Channel + Send + Sync + 'static,
+    {
+        self.single_chain_host.start_server(hint, preimage, cancellation).await
+    }
+
+    /// Query the `DataAvailabilityChallenge` contract directly for the L1 block number at which
+    /// `l2_block`'s input commitment was recorded, or `None` if it hasn't been posted yet.
+    async fn commitment_l1_block(&self, l2_block: u64) -> Result<Option<u64>> {
+        let provider = ProviderBuilder::new().on_http(self.l1_rpc_url.parse()?);
+        let contract = IDataAvailabilityChallenge::new(self.da_challenge_contract_address, provider);
+        let commitment_l1_block = contract.commitmentL1Block(U256::from(l2_block)).call().await?._0;
+
+        if commitment_l1_block.is_zero() {
+            Ok(None)
+        } else {
+            Ok(Some(commitment_l1_block.to::<u64>()))
+        }
+    }
+}
+
+/// Whether the L2 block whose input commitment was posted at `commitment_l1_block` (or that has no
+/// commitment posted yet, if `None`) is safe to include in a range proof as of
+/// `current_l1_head_number`: the challenge window, and if challenged the resolve window, must have
+/// fully elapsed.
+fn is_commitment_finalized(
+    current_l1_head_number: u64,
+    commitment_l1_block: Option<u64>,
+    da_challenge_window: u64,
+    da_resolve_window: u64,
+) -> bool {
+    match commitment_l1_block {
+        Some(commitment_l1_block) => {
+            current_l1_head_number >= commitment_l1_block + da_challenge_window + da_resolve_window
+        }
+        None => false,
+    }
+}
+
+/// Binary search `0..=latest_proposed_block_number` for the highest L2 block number that is
+/// finalized (see [`is_commitment_finalized`]), calling `commitment_l1_block_of` to look up each
+/// candidate's commitment L1 block on demand. Relies on finality being monotonic in the L2 block
+/// number (a later block's commitment is posted later, if at all, so once a candidate is unsafe
+/// every later one is too), which bounds this to `O(log latest_proposed_block_number)` lookups
+/// instead of a linear walk that could span an entire challenge+resolve window's worth of blocks.
+async fn find_finalized_boundary<F, Fut>(
+    latest_proposed_block_number: u64,
+    current_l1_head_number: u64,
+    da_challenge_window: u64,
+    da_resolve_window: u64,
+    mut commitment_l1_block_of: F,
+) -> Result<Option<u64>>
+where
+    F: FnMut(u64) -> Fut,
+    Fut: Future<Output = Result<Option<u64>>>,
+{
+    let mut lo: i128 = 0;
+    let mut hi: i128 = latest_proposed_block_number as i128;
+    let mut finalized = None;
+
+    while lo <= hi {
+        let mid = lo + (hi - lo) / 2;
+        let mid_u64 = mid as u64;
+        let commitment_l1_block = commitment_l1_block_of(mid_u64).await?;
+
+        if is_commitment_finalized(current_l1_head_number, commitment_l1_block, da_challenge_window, da_resolve_window)
+        {
+            finalized = Some(mid_u64);
+            lo = mid + 1;
+        } else {
+            hi = mid - 1;
+        }
+    }
+
+    Ok(finalized)
+}
+
+#[async_trait]
+impl OPSuccinctHost for AltDAChainHost {
+    type Args = CeloSingleChainHost;
+    type WitnessGenerator = OnlineWitnessGenerator;
+
+    fn witness_generator(&self) -> &Self::WitnessGenerator {
+        self.single_chain_host.witness_generator()
+    }
+
+    async fn fetch(
+        &self,
+        l2_start_block: u64,
+        l2_end_block: u64,
+        l1_head_hash: Option<B256>,
+        safe_db_fallback: bool,
+    ) -> Result<Self::Args> {
+        self.single_chain_host
+            .fetch(l2_start_block, l2_end_block, l1_head_hash, safe_db_fallback)
+            .await
+    }
+
+    fn get_l1_head_hash(&self, args: &Self::Args) -> Option<B256> {
+        self.single_chain_host.get_l1_head_hash(args)
+    }
+
+    /// Binary search L2 blocks `0..=latest_proposed_block_number`, and return the highest one
+    /// whose input commitment has passed both the challenge window and, if
+    /// challenged, the resolve window as of the current L1 head. Blocks newer than that are not
+    /// yet safe to include in a range proof, since their commitment could still be successfully
+    /// challenged (which would invalidate the input data the client program derived from).
+    async fn get_finalized_l2_block_number(
+        &self,
+        fetcher: &OPSuccinctDataFetcher,
+        latest_proposed_block_number: u64,
+    ) -> Result<Option<u64>> {
+        let current_l1_head = fetcher.get_l1_head().await?;
+
+        find_finalized_boundary(
+            latest_proposed_block_number,
+            current_l1_head.number,
+            self.da_challenge_window,
+            self.da_resolve_window,
+            |candidate| self.commitment_l1_block(candidate),
+        )
+        .await
+    }
+
+    /// Return the L1 head hash of the lowest L1 block at which every commitment up to
+    /// `l2_end_block` satisfies the challenge/resolve invariant described above. Falls back to
+    /// timestamp-based estimation when SafeDB is unavailable, mirroring the ETH DA host. If no
+    /// commitment has been recorded for `l2_end_block` yet, this returns the wrapped ETH DA host's
+    /// safe L1 head, which is not gated on the challenge/resolve windows.
+    async fn calculate_safe_l1_head(
+        &self,
+        fetcher: &OPSuccinctDataFetcher,
+        l2_end_block: u64,
+        safe_db_fallback: bool,
+    ) -> Result<B256> {
+        let commitment_l1_block = match self.commitment_l1_block(l2_end_block).await? {
+            Some(block) => block,
+            None => {
+                // The commitment for this block hasn't landed on L1 yet, so there is nothing
+                // to gate on beyond the usual ETH DA offset logic.
+                return self
+                    .single_chain_host
+                    .calculate_safe_l1_head(fetcher, l2_end_block, safe_db_fallback)
+                    .await;
+            }
+        };
+
+        let safe_l1_block_number = commitment_l1_block + self.da_challenge_window + self.da_resolve_window;
+
+        if let Some(hash) = fetcher.get_l1_head_hash_if_available(safe_l1_block_number, safe_db_fallback).await? {
+            return Ok(hash);
+        }
+
+        // SafeDB unavailable: fall back to timestamp-based estimation, same as the Celestia host
+        // does when Blobstream state can't be read directly.
+        fetcher.estimate_l1_head_from_timestamp(safe_l1_block_number).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_commitment_finalized_at_exact_boundary() {
+        assert!(is_commitment_finalized(1_000, Some(900), 50, 50));
+    }
+
+    #[test]
+    fn is_commitment_finalized_one_block_before_boundary() {
+        assert!(!is_commitment_finalized(999, Some(900), 50, 50));
+    }
+
+    #[test]
+    fn is_commitment_finalized_without_a_commitment_is_never_finalized() {
+        assert!(!is_commitment_finalized(u64::MAX, None, 50, 50));
+    }
+
+    #[tokio::test]
+    async fn find_finalized_boundary_walks_back_past_unsafe_blocks_to_a_safe_one() {
+        // Blocks 0..=5 have commitments old enough to be finalized; 6..=8 have commitments that
+        // are still within the challenge/resolve window; 9..=10 have no commitment posted yet.
+        let result = find_finalized_boundary(10, 1_000, 50, 50, |candidate| async move {
+            Ok(match candidate {
+                0..=5 => Some(100),
+                6..=8 => Some(950),
+                _ => None,
+            })
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(result, Some(5));
+    }
+
+    #[tokio::test]
+    async fn find_finalized_boundary_returns_none_if_nothing_is_finalized_yet() {
+        let result = find_finalized_boundary(10, 1_000, 50, 50, |_candidate| async move { Ok(None) })
+            .await
+            .unwrap();
+
+        assert_eq!(result, None);
+    }
+}
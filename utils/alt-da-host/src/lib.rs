@@ -0,0 +1 @@
+pub mod alt_da;
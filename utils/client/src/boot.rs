@@ -11,76 +11,81 @@ use sha2::{Digest, Sha256};
 // ABI encoding of AggregationOutputs is 6 * 32 bytes.
 pub const AGGREGATION_OUTPUTS_SIZE: usize = 6 * 32;
 
-/// Hash the serialized rollup config using SHA256. Note: The rollup config is never unrolled
-/// on-chain, so switching to a different hash function is not a concern, as long as the config hash
-/// is consistent with the one on the contract.
-pub fn hash_rollup_config(config: &CeloRollupConfig) -> B256 {
-    let serialized_config = {
-        // Manually construct the JSON to match the RPC response format
-        let full_config = serde_json::json!({
-            "genesis": {
-                "l1": {
-                    "hash": format!("0x{:x}", config.op_rollup_config.genesis.l1.hash),
-                    "number": config.op_rollup_config.genesis.l1.number,
-                },
-                "l2": {
-                    "hash": format!("0x{:x}", config.op_rollup_config.genesis.l2.hash),
-                    "number": config.op_rollup_config.genesis.l2.number,
-                },
-                "l2_time": config.op_rollup_config.genesis.l2_time,
-                "system_config": config.op_rollup_config.genesis.system_config.as_ref().map(|sc| {
-                    serde_json::json!({
-                        "batcherAddr": format!("0x{:x}", sc.batcher_address),
-                        "overhead": format!("0x{:064x}", sc.overhead),
-                        "scalar": format!("0x{:064x}", sc.scalar),
-                        "gasLimit": sc.gas_limit,
-                        "eip1559Params": format!("0x{:016x}",
-                            (sc.eip1559_denominator.unwrap_or(0) as u64) |
-                            ((sc.eip1559_elasticity.unwrap_or(0) as u64) << 8)
-                        ),
-                        "operatorFeeParams": format!("0x{:064x}",
-                            (sc.operator_fee_scalar.unwrap_or(0) as u128) |
-                            ((sc.operator_fee_constant.unwrap_or(0) as u128) << 64)
-                        ),
-                    })
-                }),
+/// Manually construct the JSON representation of `config` to match the RPC response format that
+/// op-node's `rollupConfigHash` is computed over. Pulled out of [`hash_rollup_config`] so tests can
+/// assert this JSON against a checked-in golden fixture, rather than only pinning the resulting
+/// hash.
+fn rollup_config_to_json(config: &CeloRollupConfig) -> serde_json::Value {
+    serde_json::json!({
+        "genesis": {
+            "l1": {
+                "hash": format!("0x{:x}", config.op_rollup_config.genesis.l1.hash),
+                "number": config.op_rollup_config.genesis.l1.number,
             },
-            "block_time": config.op_rollup_config.block_time,
-            "max_sequencer_drift": config.op_rollup_config.max_sequencer_drift,
-            "seq_window_size": config.op_rollup_config.seq_window_size,
-            "channel_timeout": config.op_rollup_config.channel_timeout,
-            "l1_chain_id": config.op_rollup_config.l1_chain_id,
-            "l2_chain_id": config.op_rollup_config.l2_chain_id,
-            "regolith_time": config.op_rollup_config.hardforks.regolith_time.unwrap_or(0),
-            // "cel2_time": config.hardforks.cel2_time.unwrap_or(0),
-            "canyon_time": config.op_rollup_config.hardforks.canyon_time.unwrap_or(0),
-            "delta_time": config.op_rollup_config.hardforks.delta_time.unwrap_or(0),
-            "ecotone_time": config.op_rollup_config.hardforks.ecotone_time.unwrap_or(0),
-            "fjord_time": config.op_rollup_config.hardforks.fjord_time.unwrap_or(0),
-            "granite_time": config.op_rollup_config.hardforks.granite_time.unwrap_or(0),
-            "holocene_time": config.op_rollup_config.hardforks.holocene_time.unwrap_or(0),
-            "isthmus_time": config.op_rollup_config.hardforks.isthmus_time.unwrap_or(0),
-            "batch_inbox_address": format!("0x{:x}", config.op_rollup_config.batch_inbox_address),
-            "deposit_contract_address": format!("0x{:x}", config.op_rollup_config.deposit_contract_address),
-            "l1_system_config_address": format!("0x{:x}", config.op_rollup_config.l1_system_config_address),
-            "protocol_versions_address": format!("0x{:x}", config.op_rollup_config.protocol_versions_address),
-            "chain_op_config": {
-                "eip1559Elasticity": config.op_rollup_config.chain_op_config.eip1559_elasticity,
-                "eip1559Denominator": config.op_rollup_config.chain_op_config.eip1559_denominator,
-                "eip1559DenominatorCanyon": config.op_rollup_config.chain_op_config.eip1559_denominator_canyon,
+            "l2": {
+                "hash": format!("0x{:x}", config.op_rollup_config.genesis.l2.hash),
+                "number": config.op_rollup_config.genesis.l2.number,
             },
-            "alt_da": config.op_rollup_config.alt_da_config.as_ref().map(|alt_da| {
+            "l2_time": config.op_rollup_config.genesis.l2_time,
+            "system_config": config.op_rollup_config.genesis.system_config.as_ref().map(|sc| {
                 serde_json::json!({
-                    "da_challenge_contract_address": alt_da.da_challenge_address.map(|addr| format!("0x{addr:x}")),
-                    "da_commitment_type": alt_da.da_commitment_type.as_deref(),
-                    "da_challenge_window": alt_da.da_challenge_window,
-                    "da_resolve_window": alt_da.da_resolve_window,
+                    "batcherAddr": format!("0x{:x}", sc.batcher_address),
+                    "overhead": format!("0x{:064x}", sc.overhead),
+                    "scalar": format!("0x{:064x}", sc.scalar),
+                    "gasLimit": sc.gas_limit,
+                    "eip1559Params": format!("0x{:016x}",
+                        (sc.eip1559_denominator.unwrap_or(0) as u64) |
+                        ((sc.eip1559_elasticity.unwrap_or(0) as u64) << 8)
+                    ),
+                    "operatorFeeParams": format!("0x{:064x}",
+                        (sc.operator_fee_scalar.unwrap_or(0) as u128) |
+                        ((sc.operator_fee_constant.unwrap_or(0) as u128) << 64)
+                    ),
                 })
             }),
-        });
-        serde_json::to_string_pretty(&full_config).unwrap()
-    };
-    // let serialized_config = serde_json::to_string_pretty(config).unwrap();
+        },
+        "block_time": config.op_rollup_config.block_time,
+        "max_sequencer_drift": config.op_rollup_config.max_sequencer_drift,
+        "seq_window_size": config.op_rollup_config.seq_window_size,
+        "channel_timeout": config.op_rollup_config.channel_timeout,
+        "l1_chain_id": config.op_rollup_config.l1_chain_id,
+        "l2_chain_id": config.op_rollup_config.l2_chain_id,
+        "regolith_time": config.op_rollup_config.hardforks.regolith_time.unwrap_or(0),
+        "cel2_time": config.hardforks.cel2_time.unwrap_or(0),
+        "canyon_time": config.op_rollup_config.hardforks.canyon_time.unwrap_or(0),
+        "delta_time": config.op_rollup_config.hardforks.delta_time.unwrap_or(0),
+        "ecotone_time": config.op_rollup_config.hardforks.ecotone_time.unwrap_or(0),
+        "fjord_time": config.op_rollup_config.hardforks.fjord_time.unwrap_or(0),
+        "granite_time": config.op_rollup_config.hardforks.granite_time.unwrap_or(0),
+        "holocene_time": config.op_rollup_config.hardforks.holocene_time.unwrap_or(0),
+        "isthmus_time": config.op_rollup_config.hardforks.isthmus_time.unwrap_or(0),
+        "interop_time": config.op_rollup_config.hardforks.interop_time.unwrap_or(0),
+        "jovian_time": config.op_rollup_config.hardforks.jovian_time.unwrap_or(0),
+        "batch_inbox_address": format!("0x{:x}", config.op_rollup_config.batch_inbox_address),
+        "deposit_contract_address": format!("0x{:x}", config.op_rollup_config.deposit_contract_address),
+        "l1_system_config_address": format!("0x{:x}", config.op_rollup_config.l1_system_config_address),
+        "protocol_versions_address": format!("0x{:x}", config.op_rollup_config.protocol_versions_address),
+        "chain_op_config": {
+            "eip1559Elasticity": config.op_rollup_config.chain_op_config.eip1559_elasticity,
+            "eip1559Denominator": config.op_rollup_config.chain_op_config.eip1559_denominator,
+            "eip1559DenominatorCanyon": config.op_rollup_config.chain_op_config.eip1559_denominator_canyon,
+        },
+        "alt_da": config.op_rollup_config.alt_da_config.as_ref().map(|alt_da| {
+            serde_json::json!({
+                "da_challenge_contract_address": alt_da.da_challenge_address.map(|addr| format!("0x{addr:x}")),
+                "da_commitment_type": alt_da.da_commitment_type.as_deref(),
+                "da_challenge_window": alt_da.da_challenge_window,
+                "da_resolve_window": alt_da.da_resolve_window,
+            })
+        }),
+    })
+}
+
+/// Hash the serialized rollup config using SHA256. Note: The rollup config is never unrolled
+/// on-chain, so switching to a different hash function is not a concern, as long as the config hash
+/// is consistent with the one on the contract.
+pub fn hash_rollup_config(config: &CeloRollupConfig) -> B256 {
+    let serialized_config = serde_json::to_string_pretty(&rollup_config_to_json(config)).unwrap();
 
     // Create a SHA256 hasher
     let mut hasher = Sha256::new();
@@ -123,3 +128,64 @@ impl From<BootInfo> for BootInfoStruct {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::Address;
+    use kona_genesis::RollupConfig;
+
+    use super::*;
+
+    /// The golden config is checked in at `testdata/golden_rollup_config.json`. The test below
+    /// serializes `config` itself and asserts it parses to the same JSON value as this file, so a
+    /// diff in the serializer shows up as a readable JSON diff, not just a changed hash.
+    const GOLDEN_ROLLUP_CONFIG_JSON: &str =
+        include_str!("../testdata/golden_rollup_config.json");
+
+    const GOLDEN_ROLLUP_CONFIG_HASH: &str =
+        "0xcbe47653887745faa33a7c10dcc560ab4f26d9d2a6be7e4d703e8bd06464d1c3";
+
+    /// Regression test pinning `hash_rollup_config`'s output to a checked-in golden hash, via a
+    /// checked-in golden JSON fixture. The contract that verifies this hash never unrolls the
+    /// config, so any unnoticed drift between this serializer and op-node's canonical marshaling
+    /// (e.g. a hardfork time silently dropped, as `cel2_time` once was) bricks proof verification
+    /// instead of failing a test.
+    ///
+    /// `conformance::rollup_config_hash_matches_rollup_node` is the complementary test that
+    /// compares this function's output against a live rollup node's `rollupConfigHash`, for a
+    /// known Celo chain. It is `#[ignore]`d by default and runs only when invoked with `--ignored`.
+    #[test]
+    fn hash_rollup_config_matches_golden_fixture() {
+        let config = CeloRollupConfig {
+            op_rollup_config: RollupConfig {
+                l1_chain_id: 1,
+                l2_chain_id: 42220,
+                block_time: 2,
+                max_sequencer_drift: 600,
+                seq_window_size: 3600,
+                channel_timeout: 300,
+                batch_inbox_address: Address::ZERO,
+                deposit_contract_address: Address::ZERO,
+                l1_system_config_address: Address::ZERO,
+                protocol_versions_address: Address::ZERO,
+                ..Default::default()
+            },
+            hardforks: CeloHardForkConfig { op_hardfork_config: Default::default(), cel2_time: Some(0) },
+        };
+
+        let serialized = serde_json::to_string_pretty(&rollup_config_to_json(&config)).unwrap();
+        let golden: serde_json::Value = serde_json::from_str(GOLDEN_ROLLUP_CONFIG_JSON).unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&serialized).unwrap(),
+            golden,
+            "serializer output no longer matches testdata/golden_rollup_config.json; update the \
+             fixture if this drift is intentional"
+        );
+
+        let mut hasher = Sha256::new();
+        hasher.update(serialized.as_bytes());
+        let hash = B256::from_slice(hasher.finalize().as_slice());
+        assert_eq!(hash, GOLDEN_ROLLUP_CONFIG_HASH.parse::<B256>().unwrap());
+        assert_eq!(hash, hash_rollup_config(&config));
+    }
+}
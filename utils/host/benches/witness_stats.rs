@@ -0,0 +1,63 @@
+//! Runs a fixed L2 range through `OPSuccinctHost::run` and reports `WitnessStats`, so it's
+//! possible to see which DA backend and which block range causes preimage blowup before
+//! submitting to the zkVM.
+//!
+//! This hits live L1/L2 RPC (same as `tests/conformance.rs`), so it's skipped unless
+//! `RUN_NETWORK_BENCHES` is set, to keep a routine `cargo bench --workspace` from hanging or
+//! failing in an environment without network access. Run it explicitly:
+//!
+//! ```sh
+//! RUN_NETWORK_BENCHES=1 cargo bench -p op-succinct-host-utils --bench witness_stats
+//! ```
+//!
+//! The range is fixed rather than randomized, since `WitnessStats` is only meaningful when
+//! compared run-over-run against the same L2 blocks.
+
+use celo_host::single::CeloSingleChainHost;
+use criterion::{criterion_group, criterion_main, Criterion};
+use op_succinct_host_utils::host::OPSuccinctHost;
+use tokio::runtime::Runtime;
+
+/// A small, fixed range chosen to exercise a representative mix of execution and derivation
+/// preimages without making the benchmark prohibitively slow to run locally.
+const L2_START_BLOCK: u64 = 1;
+const L2_END_BLOCK: u64 = 10;
+
+/// Env var gating this benchmark's live RPC calls; see the module docs above.
+const RUN_NETWORK_BENCHES_ENV_VAR: &str = "RUN_NETWORK_BENCHES";
+
+fn bench_witness_stats(c: &mut Criterion) {
+    if std::env::var(RUN_NETWORK_BENCHES_ENV_VAR).is_err() {
+        eprintln!(
+            "skipping range_witness_generation: hits live L1/L2 RPC, set {RUN_NETWORK_BENCHES_ENV_VAR}=1 to run it"
+        );
+        return;
+    }
+
+    let rt = Runtime::new().expect("failed to build tokio runtime");
+
+    c.bench_function("range_witness_generation", |b| {
+        b.to_async(&rt).iter(|| async {
+            let host = CeloSingleChainHost::default();
+
+            let args = host
+                .fetch(L2_START_BLOCK, L2_END_BLOCK, None, true)
+                .await
+                .expect("failed to fetch host args");
+            let (_witness, stats) = host.run(&args, None).await.expect("failed to run host");
+
+            eprintln!(
+                "hints={} preimages={} preimage_bytes={} peak_oracle_bytes={} host={:?} client={:?}",
+                stats.hint_requests,
+                stats.total_preimage_requests(),
+                stats.total_preimage_bytes(),
+                stats.peak_oracle_size_bytes,
+                stats.host_duration,
+                stats.client_duration,
+            );
+        });
+    });
+}
+
+criterion_group!(benches, bench_witness_stats);
+criterion_main!(benches);
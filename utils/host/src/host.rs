@@ -1,20 +1,75 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
 use alloy_primitives::B256;
+use alt_da_host::alt_da::AltDAChainHost;
 use anyhow::Result;
 use async_trait::async_trait;
 use celo_host::single::CeloSingleChainHost;
 use hana_host::celestia::CelestiaChainHost;
 use kona_host::single::SingleChainHostError;
 use kona_preimage::{BidirectionalChannel, Channel};
+use thiserror::Error;
 use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    fetcher::OPSuccinctDataFetcher,
+    stats::{CountingChannel, WitnessStats, WitnessStatsCollector},
+    witness_generation::WitnessGenerator,
+};
+
+/// The grace period given to a DA host's server task to exit cleanly after `run` signals
+/// cancellation, before falling back to a hard `abort()`.
+const SERVER_SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(5);
 
-use crate::{fetcher::OPSuccinctDataFetcher, witness_generation::WitnessGenerator};
+/// Errors from [`OPSuccinctHost::run`].
+#[derive(Debug, Error)]
+pub enum RunError {
+    /// The caller-supplied overall deadline elapsed before witness generation finished.
+    #[error("witness generation did not complete within the {0:?} deadline")]
+    DeadlineExceeded(Duration),
+    /// The preimage/hint server task failed or panicked.
+    #[error("preimage server task failed: {0}")]
+    ServerTask(#[from] tokio::task::JoinError),
+    /// Any other failure from fetching, serving, or executing the witness.
+    #[error(transparent)]
+    Host(#[from] anyhow::Error),
+}
+
+/// Signal `server_task` to shut down cooperatively via `cancellation`, then give it a bounded
+/// grace period to flush any in-flight preimage writes before falling back to a hard `abort()`.
+/// Unlike the upstream, we don't wait for the server task unboundedly, as it will otherwise hang
+/// if the DA backend's server loop never notices its input channel closing.
+async fn shutdown_server_task(
+    cancellation: CancellationToken,
+    server_task: JoinHandle<Result<(), SingleChainHostError>>,
+) -> Result<(), RunError> {
+    cancellation.cancel();
+    let abort_handle = server_task.abort_handle();
+    match tokio::time::timeout(SERVER_SHUTDOWN_GRACE_PERIOD, server_task).await {
+        Ok(Ok(Ok(()))) => Ok(()),
+        Ok(Ok(Err(err))) => Err(RunError::Host(err.into())),
+        Ok(Err(join_err)) => Err(RunError::ServerTask(join_err)),
+        Err(_) => {
+            abort_handle.abort();
+            Ok(())
+        }
+    }
+}
 
 #[async_trait]
 pub trait PreimageServerStarter {
+    /// Start the preimage/hint server. The server loop should exit as soon as possible once
+    /// `cancellation` is cancelled, rather than requiring the caller to `abort()` the returned
+    /// task.
     async fn start_server<C>(
         &self,
         hint: C,
         preimage: C,
+        cancellation: CancellationToken,
     ) -> Result<JoinHandle<Result<(), SingleChainHostError>>, SingleChainHostError>
     where
         C: Channel + Send + Sync + 'static;
@@ -26,11 +81,12 @@ impl PreimageServerStarter for CeloSingleChainHost {
         &self,
         hint: C,
         preimage: C,
+        cancellation: CancellationToken,
     ) -> Result<JoinHandle<Result<(), SingleChainHostError>>, SingleChainHostError>
     where
         C: Channel + Send + Sync + 'static,
     {
-        self.start_server(hint, preimage).await
+        self.start_server(hint, preimage, cancellation).await
     }
 }
 
@@ -40,11 +96,27 @@ impl PreimageServerStarter for CelestiaChainHost {
         &self,
         hint: C,
         preimage: C,
+        cancellation: CancellationToken,
+    ) -> Result<JoinHandle<Result<(), SingleChainHostError>>, SingleChainHostError>
+    where
+        C: Channel + Send + Sync + 'static,
+    {
+        self.start_server(hint, preimage, cancellation).await
+    }
+}
+
+#[async_trait]
+impl PreimageServerStarter for AltDAChainHost {
+    async fn start_server<C>(
+        &self,
+        hint: C,
+        preimage: C,
+        cancellation: CancellationToken,
     ) -> Result<JoinHandle<Result<(), SingleChainHostError>>, SingleChainHostError>
     where
         C: Channel + Send + Sync + 'static,
     {
-        self.start_server(hint, preimage).await
+        self.start_server(hint, preimage, cancellation).await
     }
 }
 
@@ -73,22 +145,58 @@ pub trait OPSuccinctHost: Send + Sync + 'static {
 
     /// Run the host and client program.
     ///
-    /// Returns the witness which can be supplied to the zkVM.
+    /// Returns the witness which can be supplied to the zkVM, along with [`WitnessStats`]
+    /// profiling how much hint/preimage traffic the range required. The counting wrapper is
+    /// DA-agnostic, so this works uniformly for every `OPSuccinctHost` implementor.
+    ///
+    /// If `deadline` is set and witness generation hasn't finished by then, the server task is
+    /// cancelled and [`RunError::DeadlineExceeded`] is returned rather than hanging the caller
+    /// (e.g. the proposer) indefinitely.
     async fn run(
         &self,
         args: &Self::Args,
-    ) -> Result<<Self::WitnessGenerator as WitnessGenerator>::WitnessData> {
-        let preimage = BidirectionalChannel::new()?;
-        let hint = BidirectionalChannel::new()?;
+        deadline: Option<Duration>,
+    ) -> Result<(<Self::WitnessGenerator as WitnessGenerator>::WitnessData, WitnessStats), RunError>
+    {
+        let preimage = BidirectionalChannel::new().map_err(anyhow::Error::from)?;
+        let hint = BidirectionalChannel::new().map_err(anyhow::Error::from)?;
+
+        let collector = Arc::new(WitnessStatsCollector::default());
+        let cancellation = CancellationToken::new();
+
+        let server_task = args
+            .start_server(
+                CountingChannel::hint(hint.host, collector.clone()),
+                CountingChannel::preimage(preimage.host, collector.clone()),
+                cancellation.clone(),
+            )
+            .await
+            .map_err(anyhow::Error::from)?;
+
+        let run_witness_generator = async {
+            let client_start = Instant::now();
+            let witness = self.witness_generator().run(preimage.client, hint.client).await?;
+            anyhow::Ok((witness, client_start.elapsed()))
+        };
 
-        let server_task = args.start_server(hint.host, preimage.host).await?;
+        let (witness, client_duration) = match deadline {
+            Some(deadline) => match tokio::time::timeout(deadline, run_witness_generator).await {
+                Ok(result) => result?,
+                Err(_) => {
+                    // The deadline firing is itself the error we report, but the server task
+                    // still deserves the same bounded-wait-then-abort shutdown as the success
+                    // path, so any in-flight preimage write isn't dropped mid-flush and no socket
+                    // is leaked by aborting out from under it immediately.
+                    let _ = shutdown_server_task(cancellation, server_task).await;
+                    return Err(RunError::DeadlineExceeded(deadline));
+                }
+            },
+            None => run_witness_generator.await?,
+        };
 
-        let witness = self.witness_generator().run(preimage.client, hint.client).await?;
-        // Unlike the upstream, manually abort the server task, as it will hang if you wait for both
-        // tasks to complete.
-        server_task.abort();
+        shutdown_server_task(cancellation, server_task).await?;
 
-        Ok(witness)
+        Ok((witness, collector.finish(client_duration)))
     }
 
     /// Get the L1 head hash from the host args.
@@ -0,0 +1,176 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use kona_preimage::{Channel, PreimageKeyType};
+
+/// Telemetry collected while [`OPSuccinctHost::run`](crate::host::OPSuccinctHost::run) serves a
+/// range proof's witness, to help profile where preimage fetches blow up for a given DA backend
+/// or L2 range before submitting to the zkVM.
+#[derive(Clone, Debug, Default)]
+pub struct WitnessStats {
+    /// Number of hint requests served to the client program.
+    pub hint_requests: u64,
+    /// Number of preimages served, broken down by `PreimageKeyType`.
+    pub preimage_requests_by_type: HashMap<PreimageKeyType, u64>,
+    /// Total bytes of preimage data served, broken down by `PreimageKeyType`.
+    pub preimage_bytes_by_type: HashMap<PreimageKeyType, u64>,
+    /// Host-side work: the summed time between the host reading each hint or preimage key off the
+    /// channel and writing its response back.
+    pub host_duration: Duration,
+    /// Wall-clock time spent in the client program's witness execution.
+    pub client_duration: Duration,
+    /// Peak number of bytes held in the in-memory preimage oracle at any point during execution.
+    pub peak_oracle_size_bytes: u64,
+}
+
+impl WitnessStats {
+    /// Total number of preimages served across all `PreimageKeyType`s.
+    pub fn total_preimage_requests(&self) -> u64 {
+        self.preimage_requests_by_type.values().sum()
+    }
+
+    /// Total bytes of preimage data served across all `PreimageKeyType`s.
+    pub fn total_preimage_bytes(&self) -> u64 {
+        self.preimage_bytes_by_type.values().sum()
+    }
+}
+
+/// Shared counters updated by [`CountingChannel`] as the host serves hints and preimages. Kept
+/// separate from [`WitnessStats`] so the counters can be written from behind an `Arc` while the
+/// channel is split across the host and client tasks, and snapshotted into a [`WitnessStats`] once
+/// both tasks have finished.
+#[derive(Default)]
+pub struct WitnessStatsCollector {
+    hint_requests: AtomicU64,
+    preimage_requests_by_type: std::sync::Mutex<HashMap<PreimageKeyType, u64>>,
+    preimage_bytes_by_type: std::sync::Mutex<HashMap<PreimageKeyType, u64>>,
+    oracle_size_bytes: AtomicUsize,
+    peak_oracle_size_bytes: AtomicU64,
+    /// Accumulated time between a hint or preimage key being read off the channel and the host's
+    /// response being written back, i.e. the host's own fetch/processing work for that DA
+    /// backend, rather than the time spent idle in `read_exact` waiting for the client to send
+    /// the next request.
+    host_busy_nanos: AtomicU64,
+}
+
+impl WitnessStatsCollector {
+    fn record_hint(&self, elapsed: Duration) {
+        self.hint_requests.fetch_add(1, Ordering::Relaxed);
+        self.host_busy_nanos.fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    /// Record a preimage of `key_type` with `len` bytes having been served, and update the peak
+    /// in-memory oracle size. The oracle never evicts entries during a single run, so the running
+    /// total of bytes served is also its current size.
+    fn record_preimage(&self, key_type: PreimageKeyType, len: usize, elapsed: Duration) {
+        *self.preimage_requests_by_type.lock().unwrap().entry(key_type).or_default() += 1;
+        *self.preimage_bytes_by_type.lock().unwrap().entry(key_type).or_default() += len as u64;
+        self.host_busy_nanos.fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+
+        let oracle_size = self.oracle_size_bytes.fetch_add(len, Ordering::Relaxed) + len;
+        self.peak_oracle_size_bytes.fetch_max(oracle_size as u64, Ordering::Relaxed);
+    }
+
+    /// Snapshot the counters collected so far into a [`WitnessStats`]. `client_duration` is the
+    /// wall-clock time measured by the caller around the witness generator's run; `host_duration`
+    /// is derived from the accumulated time spent inside instrumented channel calls, since the
+    /// host and client run concurrently and a single wall-clock split between them isn't
+    /// otherwise observable.
+    pub fn finish(&self, client_duration: Duration) -> WitnessStats {
+        WitnessStats {
+            hint_requests: self.hint_requests.load(Ordering::Relaxed),
+            preimage_requests_by_type: self.preimage_requests_by_type.lock().unwrap().clone(),
+            preimage_bytes_by_type: self.preimage_bytes_by_type.lock().unwrap().clone(),
+            host_duration: Duration::from_nanos(self.host_busy_nanos.load(Ordering::Relaxed)),
+            client_duration,
+            peak_oracle_size_bytes: self.peak_oracle_size_bytes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// The host-side bookkeeping [`CountingChannel`] threads between reading a hint/key off the wire
+/// and writing the corresponding response, so the gap between the two (the host's actual
+/// fetch/processing time) can be attributed once the response goes out.
+enum PendingRead {
+    Hint(Instant),
+    Preimage(PreimageKeyType, Instant),
+}
+
+/// A [`Channel`] adapter that attributes host-side fetch/processing time to hint requests and
+/// preimage fetches as they cross the wire, without knowing anything about the underlying DA
+/// backend. Wrapping the host endpoints of both `BidirectionalChannel`s in `OPSuccinctHost::run` makes
+/// this instrumentation available uniformly for every `OPSuccinctHost` implementor.
+///
+/// Both the hint and preimage channels follow a request/response shape: the client writes a hint
+/// or a 32-byte preimage key (type-tagged in its most significant byte), the host does whatever
+/// work is needed to satisfy it, and the host writes back an ack or the preimage data. The time
+/// spent inside `read_exact` itself is the host sitting idle waiting for the client's next
+/// request, so this adapter instead measures the gap between a read completing and the matching
+/// write, which is when the host is actually doing DA-backend work.
+pub struct CountingChannel<C> {
+    inner: C,
+    collector: Arc<WitnessStatsCollector>,
+    kind: ChannelKind,
+    pending_read: std::sync::Mutex<Option<PendingRead>>,
+}
+
+enum ChannelKind {
+    Hint,
+    Preimage,
+}
+
+impl<C> CountingChannel<C> {
+    pub fn hint(inner: C, collector: Arc<WitnessStatsCollector>) -> Self {
+        Self { inner, collector, kind: ChannelKind::Hint, pending_read: std::sync::Mutex::new(None) }
+    }
+
+    pub fn preimage(inner: C, collector: Arc<WitnessStatsCollector>) -> Self {
+        Self { inner, collector, kind: ChannelKind::Preimage, pending_read: std::sync::Mutex::new(None) }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> Channel for CountingChannel<C>
+where
+    C: Channel + Send + Sync,
+{
+    async fn read(&self, buf: &mut [u8]) -> kona_preimage::errors::PreimageOracleResult<usize> {
+        self.inner.read(buf).await
+    }
+
+    async fn read_exact(&self, buf: &mut [u8]) -> kona_preimage::errors::PreimageOracleResult<usize> {
+        let n = self.inner.read_exact(buf).await?;
+        let read_done = Instant::now();
+
+        match self.kind {
+            ChannelKind::Hint => *self.pending_read.lock().unwrap() = Some(PendingRead::Hint(read_done)),
+            ChannelKind::Preimage if buf.len() == 32 => {
+                let key_type = PreimageKeyType::try_from(buf[0]).unwrap_or(PreimageKeyType::Keccak256);
+                *self.pending_read.lock().unwrap() = Some(PendingRead::Preimage(key_type, read_done));
+            }
+            ChannelKind::Preimage => {}
+        }
+
+        Ok(n)
+    }
+
+    async fn write(&self, buf: &[u8]) -> kona_preimage::errors::PreimageOracleResult<usize> {
+        let n = self.inner.write(buf).await?;
+
+        match self.pending_read.lock().unwrap().take() {
+            Some(PendingRead::Hint(read_done)) => self.collector.record_hint(read_done.elapsed()),
+            Some(PendingRead::Preimage(key_type, read_done)) => {
+                self.collector.record_preimage(key_type, n, read_done.elapsed())
+            }
+            None => {}
+        }
+
+        Ok(n)
+    }
+}
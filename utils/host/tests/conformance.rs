@@ -0,0 +1,28 @@
+//! Conformance test asserting that `hash_rollup_config` agrees with op-node's canonical
+//! marshaling, rather than just with itself.
+//!
+//! This hits a real rollup node RPC, so it is `#[ignore]`d by default. Run it explicitly (e.g. in
+//! a scheduled CI job) whenever `hash_rollup_config` changes:
+//!
+//! ```sh
+//! cargo test -p op-succinct-host-utils --test conformance -- --ignored
+//! ```
+
+use op_succinct_client_utils::boot::hash_rollup_config;
+use op_succinct_host_utils::fetcher::OPSuccinctDataFetcher;
+
+/// Loads the live rollup config for a known Celo chain via `OPSuccinctDataFetcher`, re-serializes
+/// it through `hash_rollup_config`, and checks the result byte-for-byte against the
+/// `rollupConfigHash` returned by the rollup node's RPC. A mismatch here means the serializer has
+/// drifted from op-node and proof verification would brick on-chain.
+#[tokio::test]
+#[ignore = "requires network access to a live rollup node"]
+async fn rollup_config_hash_matches_rollup_node() {
+    let fetcher = OPSuccinctDataFetcher::new().await.expect("failed to construct data fetcher");
+
+    let rollup_config = fetcher.get_celo_rollup_config().await.expect("failed to fetch rollup config");
+    let expected_hash =
+        fetcher.get_rollup_config_hash().await.expect("failed to fetch rollupConfigHash from rollup node");
+
+    assert_eq!(hash_rollup_config(&rollup_config), expected_hash);
+}